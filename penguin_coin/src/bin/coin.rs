@@ -1,3 +1,4 @@
+use num_bigint::BigUint;
 use penguin_coin::chapter_one;
 use thiserror::Error;
 
@@ -10,7 +11,7 @@ enum CoinErrors {
 type Result<T> = std::result::Result<T, CoinErrors>;
 
 fn main() -> Result<()> {
-    let x = chapter_one::FieldElement::new(4, 3)?;
+    let x = chapter_one::FieldElement::new(BigUint::from(4u8), BigUint::from(3u8))?;
     println!("{:#?}", x);
     Ok(())
 }
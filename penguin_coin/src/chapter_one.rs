@@ -1,45 +1,245 @@
-use mod_exp::mod_exp;
+use num_bigint::{BigInt, BigUint};
 use std::ops;
 use thiserror::Error;
 
+/// Arithmetic surface `Point` needs from its coordinate type, extracted so curve code can be
+/// written once against the trait instead of hard-coding `i64` or `FieldElement` — a future
+/// tower/extension field or a Montgomery-form implementation can be swapped in without
+/// touching `Point` itself. Modeled after the `bn` crate's `Field` abstraction.
+pub trait Field: Sized + Clone + Eq + std::fmt::Debug + std::fmt::Display {
+    type Error: std::error::Error + PartialEq;
+
+    /// The additive identity in the same field as `self`. Takes `&self` rather than being a
+    /// bare associated function because `FieldElement`'s identity depends on a runtime prime
+    /// it doesn't otherwise carry.
+    fn zero(&self) -> Self;
+    /// The multiplicative identity in the same field as `self`. See `zero` for why this
+    /// borrows rather than standing alone.
+    fn one(&self) -> Self;
+    fn add(self, other: Self) -> std::result::Result<Self, Self::Error>;
+    fn sub(self, other: Self) -> std::result::Result<Self, Self::Error>;
+    fn mul(self, other: Self) -> std::result::Result<Self, Self::Error>;
+    fn div(self, other: Self) -> std::result::Result<Self, Self::Error>;
+    fn pow(self, exp: i64) -> Self;
+    fn squared(self) -> Self {
+        self.pow(2)
+    }
+    /// The multiplicative inverse, or an error if `self` is zero.
+    fn inverse(self) -> std::result::Result<Self, Self::Error>;
+    fn is_zero(&self) -> bool;
+    fn is_even(&self) -> bool;
+    fn neg(self) -> Self;
+    /// Solves `y^2 = self`, as needed to decompress a point from just its x-coordinate.
+    fn sqrt(self) -> std::result::Result<Self, Self::Error>;
+}
+
+/// Plain integer arithmetic, so `Point<i64>` works over the integers. This is not a field:
+/// `div` and `inverse` truncate toward zero and panic on a zero divisor instead of returning
+/// an error, and `sqrt` returns the truncated real square root without checking that `self`
+/// is a perfect square.
+impl Field for i64 {
+    type Error = std::convert::Infallible;
+
+    fn zero(&self) -> Self {
+        0
+    }
+
+    fn one(&self) -> Self {
+        1
+    }
+
+    fn add(self, other: Self) -> std::result::Result<Self, Self::Error> {
+        Ok(self + other)
+    }
+
+    fn sub(self, other: Self) -> std::result::Result<Self, Self::Error> {
+        Ok(self - other)
+    }
+
+    fn mul(self, other: Self) -> std::result::Result<Self, Self::Error> {
+        Ok(self * other)
+    }
+
+    fn div(self, other: Self) -> std::result::Result<Self, Self::Error> {
+        Ok(self / other)
+    }
+
+    fn pow(self, exp: i64) -> Self {
+        i64::pow(self, exp as u32)
+    }
+
+    fn inverse(self) -> std::result::Result<Self, Self::Error> {
+        Ok(1 / self)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+
+    fn is_even(&self) -> bool {
+        self % 2 == 0
+    }
+
+    fn neg(self) -> Self {
+        -self
+    }
+
+    fn sqrt(self) -> std::result::Result<Self, Self::Error> {
+        Ok((self as f64).sqrt() as i64)
+    }
+}
+
 #[derive(Error, Debug, PartialEq)]
 pub enum FiniteSetError {
     #[error("Number, {0}, is invalid as it is larger then the prime, {1}.")]
-    NumberTooLarge(i64, i64),
-    #[error("Number, {0}, is less then zero, which is invalid")]
-    NumberLessThanZero(i64),
+    NumberTooLarge(BigUint, BigUint),
     #[error("The prime value must be equal to add. Provide primes {0} and {1}.")]
-    MisMatchedPrimes(i64, i64),
+    MisMatchedPrimes(BigUint, BigUint),
+    #[error("{0} is not a quadratic residue modulo the prime; it has no square root.")]
+    NonResidue(BigUint),
+    #[error("cannot divide by or invert 0 in the field modulo {0}")]
+    DivisionByZero(BigUint),
 }
 
 type Result<T> = std::result::Result<T, FiniteSetError>;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct FieldElement {
-    num: i64,
-    prime: i64,
+    num: BigUint,
+    prime: BigUint,
 }
 
 impl FieldElement {
-    pub fn new(num: i64, prime: i64) -> Result<Self> {
+    pub fn new(num: BigUint, prime: BigUint) -> Result<Self> {
         if num >= prime {
             return Err(FiniteSetError::NumberTooLarge(num, prime));
         }
-        if num < 0 {
-            return Err(FiniteSetError::NumberLessThanZero(num));
-        }
         Ok(FieldElement { num, prime })
     }
 
+    /// The element's raw residue, e.g. to re-interpret it modulo a different prime.
+    pub fn num(&self) -> &BigUint {
+        &self.num
+    }
+
+    /// Modular exponentiation, reducing `exp` into `[0, prime - 1)` first so negative exponents
+    /// compute the same Fermat inverse as [`FieldElement::inverse`]. Unlike `div`/`inverse`,
+    /// this has no zero guard: `Field::pow`'s signature returns `Self` rather than a `Result`,
+    /// so `FieldElement::new(0, p).pow(-1)` silently returns `0` instead of erroring. Prefer
+    /// `inverse()` (or `div`, which is built on it) over a negative `pow` when `self` might be
+    /// zero; this method is only guaranteed correct for non-negative exponents or a non-zero
+    /// base.
     pub fn pow(self, exp: i64) -> Self {
-        let n = exp % (self.prime - 1);
-        let n = if n < 0 { n + (self.prime - 1) } else { n };
-        let num = mod_exp(self.num, n, self.prime);
+        if exp > 0 && self.num == BigUint::from(0u8) {
+            return self;
+        }
+        let order = BigInt::from(self.prime.clone()) - BigInt::from(1);
+        let mut n = BigInt::from(exp) % &order;
+        if n < BigInt::from(0) {
+            n += &order;
+        }
+        let n = n.to_biguint().expect("reduced exponent is non-negative");
+        self.pow_mod(&n)
+    }
+
+    /// Modular exponentiation by an exponent too large to fit in an `i64`, e.g. `prime - 2`
+    /// in the Fermat inverse below, or the Tonelli-Shanks exponents used by square roots.
+    pub(crate) fn pow_mod(self, exp: &BigUint) -> Self {
         FieldElement {
-            num: if num < 0 { num + self.prime } else { num },
+            num: self.num.modpow(exp, &self.prime),
             prime: self.prime,
         }
     }
+
+    /// Multiplicative inverse via Fermat's little theorem: `self^(prime - 2) mod prime`.
+    /// Errors rather than returning a meaningless result when `self` is 0, which has no
+    /// multiplicative inverse.
+    pub fn inverse(self) -> Result<Self> {
+        if self.num == BigUint::from(0u8) {
+            return Err(FiniteSetError::DivisionByZero(self.prime));
+        }
+        let exp = &self.prime - BigUint::from(2u8);
+        Ok(self.pow_mod(&exp))
+    }
+
+    /// Solves `y^2 = self` for `y`, as needed to decompress a point from just its x-coordinate.
+    /// Errors when `self` is a non-residue, i.e. no square root exists modulo `prime`.
+    pub fn sqrt(self) -> Result<Self> {
+        if self.is_zero() {
+            return Ok(self);
+        }
+        let four = BigUint::from(4u8);
+        let candidate = if &self.prime % &four == BigUint::from(3u8) {
+            let exp = (&self.prime + BigUint::from(1u8)) / &four;
+            self.clone().pow_mod(&exp)
+        } else {
+            self.clone().tonelli_shanks()?
+        };
+
+        if (candidate.clone() * candidate.clone())? == self {
+            Ok(candidate)
+        } else {
+            Err(FiniteSetError::NonResidue(self.num))
+        }
+    }
+
+    /// General-case modular square root for `prime ≡ 1 (mod 4)`, where the `(prime+1)/4`
+    /// shortcut above doesn't apply.
+    fn tonelli_shanks(self) -> Result<Self> {
+        let one = BigUint::from(1u8);
+        let two = BigUint::from(2u8);
+        let prime = self.prime.clone();
+
+        let mut q = &prime - &one;
+        let mut s: u32 = 0;
+        while &q % &two == BigUint::from(0u8) {
+            q /= &two;
+            s += 1;
+        }
+
+        let euler_exp = (&prime - &one) / &two;
+        let prime_minus_one = &prime - &one;
+        let mut z_num = two.clone();
+        while (FieldElement {
+            num: z_num.clone(),
+            prime: prime.clone(),
+        })
+        .pow_mod(&euler_exp)
+        .num
+            != prime_minus_one
+        {
+            z_num += &one;
+        }
+        let z = FieldElement {
+            num: z_num,
+            prime: prime.clone(),
+        };
+
+        let mut m = s;
+        let mut c = z.pow_mod(&q);
+        let mut t = self.clone().pow_mod(&q);
+        let mut r = self.clone().pow_mod(&((&q + &one) / &two));
+
+        loop {
+            if t.num == one {
+                return Ok(r);
+            }
+            let mut i = 0u32;
+            let mut temp = t.clone();
+            while temp.num != one {
+                temp = (temp.clone() * temp)?;
+                i += 1;
+                if i == m {
+                    return Err(FiniteSetError::NonResidue(self.num));
+                }
+            }
+            let b = c.pow_mod(&BigUint::from(2u8).pow(m - i - 1));
+            m = i;
+            c = (b.clone() * b.clone())?;
+            t = (t * c.clone())?;
+            r = (r * b)?;
+        }
+    }
 }
 
 impl ops::Add<FieldElement> for FieldElement {
@@ -50,7 +250,7 @@ impl ops::Add<FieldElement> for FieldElement {
             return Err(FiniteSetError::MisMatchedPrimes(self.prime, other.prime));
         }
         Ok(FieldElement {
-            num: (self.num + other.num) % self.prime,
+            num: (self.num + other.num) % &self.prime,
             prime: self.prime,
         })
     }
@@ -66,9 +266,9 @@ impl ops::Sub<FieldElement> for FieldElement {
 
         Ok(FieldElement {
             num: if self.num < other.num {
-                (self.num - other.num) % self.prime + self.prime
+                (self.num + &self.prime - other.num) % &self.prime
             } else {
-                (self.num - other.num) % self.prime
+                (self.num - other.num) % &self.prime
             },
             prime: self.prime,
         })
@@ -82,13 +282,8 @@ impl ops::Mul<FieldElement> for FieldElement {
         if self.prime != other.prime {
             return Err(FiniteSetError::MisMatchedPrimes(self.prime, other.prime));
         }
-        let prod = self.num * other.num;
         Ok(FieldElement {
-            num: if prod < 0 {
-                prod % self.prime + self.prime
-            } else {
-                prod % self.prime
-            },
+            num: (self.num * other.num) % &self.prime,
             prime: self.prime,
         })
     }
@@ -101,13 +296,85 @@ impl ops::Div<FieldElement> for FieldElement {
         if self.prime != other.prime {
             return Err(FiniteSetError::MisMatchedPrimes(self.prime, other.prime));
         }
+        let inv = other.inverse()?;
         Ok(FieldElement {
-            num: self.num * other.pow(self.prime - 2).num % self.prime,
+            num: (self.num * inv.num) % &self.prime,
             prime: self.prime,
         })
     }
 }
 
+impl std::fmt::Display for FieldElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.num)
+    }
+}
+
+impl Field for FieldElement {
+    type Error = FiniteSetError;
+
+    fn zero(&self) -> Self {
+        FieldElement {
+            num: BigUint::from(0u8),
+            prime: self.prime.clone(),
+        }
+    }
+
+    fn one(&self) -> Self {
+        FieldElement {
+            num: BigUint::from(1u8),
+            prime: self.prime.clone(),
+        }
+    }
+
+    fn add(self, other: Self) -> std::result::Result<Self, Self::Error> {
+        ops::Add::add(self, other)
+    }
+
+    fn sub(self, other: Self) -> std::result::Result<Self, Self::Error> {
+        ops::Sub::sub(self, other)
+    }
+
+    fn mul(self, other: Self) -> std::result::Result<Self, Self::Error> {
+        ops::Mul::mul(self, other)
+    }
+
+    fn div(self, other: Self) -> std::result::Result<Self, Self::Error> {
+        ops::Div::div(self, other)
+    }
+
+    fn pow(self, exp: i64) -> Self {
+        FieldElement::pow(self, exp)
+    }
+
+    fn inverse(self) -> std::result::Result<Self, Self::Error> {
+        FieldElement::inverse(self)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == BigUint::from(0u8)
+    }
+
+    fn is_even(&self) -> bool {
+        &self.num % BigUint::from(2u8) == BigUint::from(0u8)
+    }
+
+    fn neg(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            FieldElement {
+                num: &self.prime - &self.num,
+                prime: self.prime,
+            }
+        }
+    }
+
+    fn sqrt(self) -> std::result::Result<Self, Self::Error> {
+        FieldElement::sqrt(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,20 +382,25 @@ mod tests {
     #[test]
     fn test_field_element_new() {
         assert_eq!(
-            FieldElement::new(1, 5).unwrap(),
-            FieldElement { num: 1, prime: 5 }
+            FieldElement::new(BigUint::from(1u8), BigUint::from(5u8)).unwrap(),
+            FieldElement {
+                num: BigUint::from(1u8),
+                prime: BigUint::from(5u8)
+            }
+        );
+        let too_large_error =
+            FieldElement::new(BigUint::from(7u8), BigUint::from(5u8)).unwrap_err();
+        assert_eq!(
+            too_large_error,
+            FiniteSetError::NumberTooLarge(BigUint::from(7u8), BigUint::from(5u8))
         );
-        let too_large_error = FieldElement::new(7, 5).unwrap_err();
-        assert_eq!(too_large_error, FiniteSetError::NumberTooLarge(7, 5));
-        let less_than_zero_error = FieldElement::new(-1, 5).unwrap_err();
-        assert_eq!(less_than_zero_error, FiniteSetError::NumberLessThanZero(-1));
     }
 
     #[test]
     fn test_field_element_equality() {
-        let a = FieldElement::new(2, 31).unwrap();
-        let b = FieldElement::new(2, 31).unwrap();
-        let c = FieldElement::new(15, 31).unwrap();
+        let a = FieldElement::new(BigUint::from(2u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(2u8), BigUint::from(31u8)).unwrap();
+        let c = FieldElement::new(BigUint::from(15u8), BigUint::from(31u8)).unwrap();
         assert!(a == b);
         assert!(a != c);
         assert!(!(a != b));
@@ -136,66 +408,194 @@ mod tests {
 
     #[test]
     fn test_field_element_add() {
-        let a = FieldElement::new(2, 31).unwrap();
-        let b = FieldElement::new(15, 31).unwrap();
-        assert_eq!((a + b).unwrap(), FieldElement { num: 17, prime: 31 });
-        let a = FieldElement::new(17, 31).unwrap();
-        let b = FieldElement::new(21, 31).unwrap();
-        assert_eq!((a + b).unwrap(), FieldElement { num: 7, prime: 31 });
-        let a = FieldElement::new(17, 31).unwrap();
-        let b = FieldElement::new(21, 37).unwrap();
+        let a = FieldElement::new(BigUint::from(2u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(15u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            (a + b).unwrap(),
+            FieldElement {
+                num: BigUint::from(17u8),
+                prime: BigUint::from(31u8)
+            }
+        );
+        let a = FieldElement::new(BigUint::from(17u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(21u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            (a + b).unwrap(),
+            FieldElement {
+                num: BigUint::from(7u8),
+                prime: BigUint::from(31u8)
+            }
+        );
+        let a = FieldElement::new(BigUint::from(17u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(21u8), BigUint::from(37u8)).unwrap();
         let mis_match_primes_error = (a + b).unwrap_err();
         assert_eq!(
             mis_match_primes_error,
-            FiniteSetError::MisMatchedPrimes(31, 37)
+            FiniteSetError::MisMatchedPrimes(BigUint::from(31u8), BigUint::from(37u8))
         );
     }
 
     #[test]
     fn test_field_element_sub() {
-        let a = FieldElement::new(29, 31).unwrap();
-        let b = FieldElement::new(4, 31).unwrap();
-        assert_eq!((a - b).unwrap(), FieldElement { num: 25, prime: 31 });
-        let a = FieldElement::new(15, 31).unwrap();
-        let b = FieldElement::new(30, 31).unwrap();
-        assert_eq!((a - b).unwrap(), FieldElement { num: 16, prime: 31 });
-        let a = FieldElement::new(17, 31).unwrap();
-        let b = FieldElement::new(21, 37).unwrap();
+        let a = FieldElement::new(BigUint::from(29u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(4u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            (a - b).unwrap(),
+            FieldElement {
+                num: BigUint::from(25u8),
+                prime: BigUint::from(31u8)
+            }
+        );
+        let a = FieldElement::new(BigUint::from(15u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(30u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            (a - b).unwrap(),
+            FieldElement {
+                num: BigUint::from(16u8),
+                prime: BigUint::from(31u8)
+            }
+        );
+        let a = FieldElement::new(BigUint::from(17u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(21u8), BigUint::from(37u8)).unwrap();
         let mis_match_primes_error = (a - b).unwrap_err();
         assert_eq!(
             mis_match_primes_error,
-            FiniteSetError::MisMatchedPrimes(31, 37)
+            FiniteSetError::MisMatchedPrimes(BigUint::from(31u8), BigUint::from(37u8))
         );
     }
 
     #[test]
     fn test_field_element_mul() {
-        let a = FieldElement::new(24, 31).unwrap();
-        let b = FieldElement::new(19, 31).unwrap();
-        assert_eq!((a * b).unwrap(), FieldElement { num: 22, prime: 31 });
+        let a = FieldElement::new(BigUint::from(24u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(19u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            (a * b).unwrap(),
+            FieldElement {
+                num: BigUint::from(22u8),
+                prime: BigUint::from(31u8)
+            }
+        );
     }
 
     #[test]
     fn test_field_element_pow() {
-        let a = FieldElement::new(17, 31).unwrap();
-        assert_eq!(a.pow(3), FieldElement { num: 15, prime: 31 });
-        let a = FieldElement::new(5, 31).unwrap();
-        let b = FieldElement::new(18, 31).unwrap();
-        assert_eq!((a.pow(5) * b).unwrap(), FieldElement { num: 16, prime: 31 });
+        let a = FieldElement::new(BigUint::from(17u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            a.pow(3),
+            FieldElement {
+                num: BigUint::from(15u8),
+                prime: BigUint::from(31u8)
+            }
+        );
+        let a = FieldElement::new(BigUint::from(5u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(18u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            (a.pow(5) * b).unwrap(),
+            FieldElement {
+                num: BigUint::from(16u8),
+                prime: BigUint::from(31u8)
+            }
+        );
     }
 
     #[test]
     fn test_field_element_div() {
-        let a = FieldElement::new(3, 31).unwrap();
-        let b = FieldElement::new(24, 31).unwrap();
-        assert_eq!((a / b).unwrap(), FieldElement { num: 4, prime: 31 });
-        let a = FieldElement::new(17, 31).unwrap();
-        assert_eq!(a.pow(-3), FieldElement { num: 29, prime: 31 });
-        let a = FieldElement::new(4, 31).unwrap();
-        let b = FieldElement::new(11, 31).unwrap();
+        let a = FieldElement::new(BigUint::from(3u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(24u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            (a / b).unwrap(),
+            FieldElement {
+                num: BigUint::from(4u8),
+                prime: BigUint::from(31u8)
+            }
+        );
+        let a = FieldElement::new(BigUint::from(17u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            a.pow(-3),
+            FieldElement {
+                num: BigUint::from(29u8),
+                prime: BigUint::from(31u8)
+            }
+        );
+        let a = FieldElement::new(BigUint::from(4u8), BigUint::from(31u8)).unwrap();
+        let b = FieldElement::new(BigUint::from(11u8), BigUint::from(31u8)).unwrap();
         assert_eq!(
             (a.pow(-4) * b).unwrap(),
-            FieldElement { num: 13, prime: 31 }
+            FieldElement {
+                num: BigUint::from(13u8),
+                prime: BigUint::from(31u8)
+            }
+        );
+    }
+
+    #[test]
+    fn test_field_element_div_by_zero() {
+        let a = FieldElement::new(BigUint::from(3u8), BigUint::from(31u8)).unwrap();
+        let zero = FieldElement::new(BigUint::from(0u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            (a / zero).unwrap_err(),
+            FiniteSetError::DivisionByZero(BigUint::from(31u8))
+        );
+    }
+
+    #[test]
+    fn test_field_element_pow_negative_exponent_of_zero_is_unguarded() {
+        // Pinned, not desired: `pow`'s `Self` return (mandated by `Field::pow`'s signature)
+        // can't report the same division-by-zero error `inverse()`/`div` do, so a negative
+        // exponent on zero silently returns zero. See the doc comment on `pow` — callers that
+        // might divide by zero should go through `inverse()` instead.
+        let zero = FieldElement::new(BigUint::from(0u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(zero.clone().pow(-1), zero);
+    }
+
+    #[test]
+    fn test_field_element_inverse_of_zero() {
+        let zero = FieldElement::new(BigUint::from(0u8), BigUint::from(31u8)).unwrap();
+        assert_eq!(
+            zero.inverse().unwrap_err(),
+            FiniteSetError::DivisionByZero(BigUint::from(31u8))
         );
     }
+
+    #[test]
+    fn test_field_element_sqrt() {
+        let prime = BigUint::from(223u32);
+        let value = FieldElement::new(BigUint::from(37u8), prime).unwrap();
+        let root = value.clone().sqrt().unwrap();
+        assert_eq!((root.clone() * root).unwrap(), value);
+    }
+
+    #[test]
+    fn test_field_element_sqrt_non_residue() {
+        let prime = BigUint::from(223u32);
+        let value = FieldElement::new(BigUint::from(3u8), prime).unwrap();
+        assert!(value.sqrt().is_err());
+    }
+
+    #[test]
+    fn test_field_element_sqrt_prime_one_mod_four() {
+        // 97 ≡ 1 (mod 4), so sqrt() takes the general Tonelli-Shanks path rather than the
+        // `(prime+1)/4` shortcut used for primes ≡ 3 (mod 4) above.
+        let prime = BigUint::from(97u32);
+        let value = FieldElement::new(BigUint::from(9u8), prime).unwrap();
+        let root = value.clone().sqrt().unwrap();
+        assert_eq!((root.clone() * root).unwrap(), value);
+    }
+
+    #[test]
+    fn test_field_element_sqrt_non_residue_prime_one_mod_four() {
+        let prime = BigUint::from(97u32);
+        let value = FieldElement::new(BigUint::from(5u8), prime).unwrap();
+        assert!(value.sqrt().is_err());
+    }
+
+    #[test]
+    fn test_field_element_sqrt_of_zero_prime_one_mod_four() {
+        // The Tonelli-Shanks loop never sees `t.num == 1` when `self` is 0, since every power
+        // of 0 is 0; sqrt() must short-circuit before it instead of reporting a false
+        // `NonResidue`.
+        let prime = BigUint::from(97u32);
+        let zero = FieldElement::new(BigUint::from(0u8), prime).unwrap();
+        assert_eq!(zero.clone().sqrt().unwrap(), zero);
+    }
 }
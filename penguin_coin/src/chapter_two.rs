@@ -1,53 +1,112 @@
+use crate::chapter_one::Field;
+use num_bigint::BigUint;
 use std::{fmt, ops};
 use thiserror::Error;
 
-type Result<T> = std::result::Result<T, PointError>;
+type Result<T, F> = std::result::Result<T, PointError<F>>;
 
 #[derive(Error, Debug, PartialEq)]
-pub enum PointError {
+pub enum PointError<F: Field> {
     #[error("Invalid Point: ({0}, {1}) is not on the curve")]
-    InvalidPoint(i64, i64),
-    #[error("Both X and Y must both be None of both Some(i64)")]
+    InvalidPoint(F, F),
+    #[error("Both X and Y must both be None of both Some(F)")]
     SingleInfinity,
     #[error("Points {0} and {1} are on different curves")]
-    DifferentCurves(Point, Point),
+    DifferentCurves(Point<F>, Point<F>),
     #[error("Unknown Addition for {0} and {1}")]
-    UnknownAddition(Point, Point),
+    UnknownAddition(Point<F>, Point<F>),
+    #[error(transparent)]
+    ArithmeticError(F::Error),
+}
+
+/// Lifts an `F::Error` arithmetic failure into a `PointError`. Plain `?` can't do this
+/// conversion itself: a blanket `From<F::Error>` would collide with the standard library's
+/// reflexive `impl<T> From<T> for T` once `F::Error` is left as an unconstrained associated type.
+trait FieldResultExt<T, F: Field> {
+    fn field_err(self) -> Result<T, F>;
+}
+
+impl<T, F: Field> FieldResultExt<T, F> for std::result::Result<T, F::Error> {
+    fn field_err(self) -> Result<T, F> {
+        self.map_err(PointError::ArithmeticError)
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
-pub struct Point {
-    x: Option<i64>,
-    y: Option<i64>,
-    a: i64,
-    b: i64,
+pub struct Point<F> {
+    x: Option<F>,
+    y: Option<F>,
+    a: F,
+    b: F,
 }
 
-impl Point {
-    pub fn new(x: Option<i64>, y: Option<i64>, a: i64, b: i64) -> Result<Self> {
-        let p = Point { x, y, a, b };
+impl<F: Field> Point<F> {
+    /// The point at infinity, i.e. the group's additive identity, on the curve `y^2 = x^3 + ax + b`.
+    pub fn infinity(a: F, b: F) -> Self {
+        Point {
+            x: None,
+            y: None,
+            a,
+            b,
+        }
+    }
+
+    /// Reconstructs a point from a compressed encoding: the x-coordinate plus a flag for
+    /// which of the two `y` roots (even or odd) is intended.
+    pub fn from_x(x: F, y_is_even: bool, a: F, b: F) -> Result<Self, F> {
+        let rhs = x
+            .clone()
+            .pow(3)
+            .add(a.clone().mul(x.clone()).field_err()?)
+            .field_err()?
+            .add(b.clone())
+            .field_err()?;
+        let y = rhs.sqrt().field_err()?;
+        let y = if y.is_even() == y_is_even { y } else { y.neg() };
+        Point::new(Some(x), Some(y), a, b)
+    }
+
+    /// The x-coordinate, or `None` for the point at infinity.
+    pub fn x(&self) -> Option<&F> {
+        self.x.as_ref()
+    }
+
+    pub fn new(x: Option<F>, y: Option<F>, a: F, b: F) -> Result<Self, F> {
         match (x, y) {
-            (None, None) => Ok(p),
+            (None, None) => Ok(Point::infinity(a, b)),
             (None, Some(_)) => Err(PointError::SingleInfinity),
             (Some(_), None) => Err(PointError::SingleInfinity),
             (Some(x1), Some(y1)) => {
-                if y1.pow(2) != x1.pow(3) - a * x1 + b {
+                let lhs = y1.clone().pow(2);
+                let rhs = x1
+                    .clone()
+                    .pow(3)
+                    .add(a.clone().mul(x1.clone()).field_err()?)
+                    .field_err()?
+                    .add(b.clone())
+                    .field_err()?;
+                if lhs != rhs {
                     Err(PointError::InvalidPoint(y1, x1))
                 } else {
-                    Ok(p)
+                    Ok(Point {
+                        x: Some(x1),
+                        y: Some(y1),
+                        a,
+                        b,
+                    })
                 }
             }
         }
     }
 }
 
-impl fmt::Display for Point {
+impl<F: fmt::Display> fmt::Display for Point<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let x_display = match self.x {
+        let x_display = match &self.x {
             None => "Infinity".to_string(),
             Some(v) => v.to_string(),
         };
-        let y_display = match self.y {
+        let y_display = match &self.y {
             None => "Infinity".to_string(),
             Some(v) => v.to_string(),
         };
@@ -59,10 +118,10 @@ impl fmt::Display for Point {
     }
 }
 
-impl ops::Add<Point> for Point {
-    type Output = Result<Self>;
+impl<F: Field> ops::Add<Point<F>> for Point<F> {
+    type Output = Result<Self, F>;
 
-    fn add(self, other: Self) -> Result<Self> {
+    fn add(self, other: Self) -> Result<Self, F> {
         if self.a != other.a || self.b != other.b {
             return Err(PointError::DifferentCurves(self, other));
         }
@@ -73,17 +132,30 @@ impl ops::Add<Point> for Point {
             return Ok(self);
         }
         if self.x == other.x && self.y != other.y {
-            return Ok(Point {
-                x: None,
-                y: None,
-                a: self.a,
-                b: self.b,
-            });
+            return Ok(Point::infinity(self.a, self.b));
         }
         if self.x != other.x {
-            let s = (other.y.unwrap() - self.y.unwrap()) / (other.x.unwrap() - self.x.unwrap());
-            let x = s.pow(2) - self.x.unwrap() - other.x.unwrap();
-            let y = s * (self.x.unwrap() - x) - self.y.unwrap();
+            let x1 = self.x.clone().unwrap();
+            let y1 = self.y.clone().unwrap();
+            let x2 = other.x.clone().unwrap();
+            let y2 = other.y.clone().unwrap();
+            let s = y2
+                .sub(y1.clone())
+                .field_err()?
+                .div(x2.clone().sub(x1.clone()).field_err()?)
+                .field_err()?;
+            let x = s
+                .clone()
+                .pow(2)
+                .sub(x1.clone())
+                .field_err()?
+                .sub(x2)
+                .field_err()?;
+            let y = s
+                .mul(x1.sub(x.clone()).field_err()?)
+                .field_err()?
+                .sub(y1)
+                .field_err()?;
             return Ok(Point {
                 x: Some(x),
                 y: Some(y),
@@ -92,18 +164,37 @@ impl ops::Add<Point> for Point {
             });
         }
 
-        if self == other && self.y.unwrap() == 0 {
-            return Ok(Point {
-                x: None,
-                y: None,
-                a: self.a,
-                b: self.b,
-            });
+        let x1 = self.x.clone().unwrap();
+        let y1 = self.y.clone().unwrap();
+        if self == other && y1.is_zero() {
+            return Ok(Point::infinity(self.a, self.b));
         }
         if self == other {
-            let s = (3 * self.x.unwrap().pow(2) + self.a) / (2 * self.y.unwrap());
-            let x = s.pow(2) - 2 * self.x.unwrap();
-            let y = s * (self.x.unwrap() - x) - self.y.unwrap();
+            let two_y1 = y1.clone().add(y1.clone()).field_err()?;
+            let x1_sq = x1.clone().pow(2);
+            let three_x1_sq = x1_sq
+                .clone()
+                .add(x1_sq.clone())
+                .field_err()?
+                .add(x1_sq)
+                .field_err()?;
+            let s = three_x1_sq
+                .add(self.a.clone())
+                .field_err()?
+                .div(two_y1)
+                .field_err()?;
+            let x = s
+                .clone()
+                .pow(2)
+                .sub(x1.clone())
+                .field_err()?
+                .sub(x1.clone())
+                .field_err()?;
+            let y = s
+                .mul(x1.sub(x.clone()).field_err()?)
+                .field_err()?
+                .sub(y1)
+                .field_err()?;
             return Ok(Point {
                 x: Some(x),
                 y: Some(y),
@@ -115,18 +206,89 @@ impl ops::Add<Point> for Point {
     }
 }
 
+impl<F: Field> ops::Mul<u64> for Point<F> {
+    type Output = Result<Self, F>;
+
+    /// Double-and-add: O(log n) group additions instead of n - 1 naive ones.
+    fn mul(self, scalar: u64) -> Result<Self, F> {
+        let mut result = Point::infinity(self.a.clone(), self.b.clone());
+        let mut current = self;
+        let mut n = scalar;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = (result + current.clone())?;
+            }
+            current = (current.clone() + current)?;
+            n >>= 1;
+        }
+        Ok(result)
+    }
+}
+
+impl<F: Field> ops::Mul<BigUint> for Point<F> {
+    type Output = Result<Self, F>;
+
+    /// Same double-and-add algorithm as `Mul<u64>`, for scalars too large to fit in a `u64`
+    /// (e.g. ECDSA nonces and private keys over a 256-bit curve order).
+    fn mul(self, scalar: BigUint) -> Result<Self, F> {
+        let mut result = Point::infinity(self.a.clone(), self.b.clone());
+        let mut current = self;
+        let mut n = scalar;
+        let zero = BigUint::from(0u8);
+        while n > zero {
+            if &n % 2u8 == BigUint::from(1u8) {
+                result = (result + current.clone())?;
+            }
+            current = (current.clone() + current)?;
+            n >>= 1usize;
+        }
+        Ok(result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::chapter_one::FieldElement;
+
+    #[test]
+    fn test_point_scalar_mul_known_order() {
+        let prime = BigUint::from(223u32);
+        let fe = |num: u32| FieldElement::new(BigUint::from(num), prime.clone()).unwrap();
+
+        let a = fe(0);
+        let b = fe(7);
+        let g = Point::new(Some(fe(15)), Some(fe(86)), a.clone(), b.clone()).unwrap();
+        let infinity = Point::infinity(a, b);
+
+        assert_eq!((g.clone() * 7u64).unwrap(), infinity);
+        assert_eq!((g * BigUint::from(7u8)).unwrap(), infinity);
+    }
+
+    #[test]
+    fn test_point_from_x_decompresses_correct_parity() {
+        let prime = BigUint::from(223u32);
+        let fe = |num: u32| FieldElement::new(BigUint::from(num), prime.clone()).unwrap();
+
+        let a = fe(0);
+        let b = fe(7);
+        let expected = Point::new(Some(fe(15)), Some(fe(86)), a.clone(), b.clone()).unwrap();
+
+        let even = Point::from_x(fe(15), true, a.clone(), b.clone()).unwrap();
+        let odd = Point::from_x(fe(15), false, a, b).unwrap();
+        assert_eq!(even, expected);
+        assert_ne!(odd, expected);
+    }
+
     #[test]
     fn test_point_ne() {
-        let a = Point {
+        let a = Point::<i64> {
             x: Some(3),
             y: Some(-7),
             a: 5,
             b: 7,
         };
-        let b = Point {
+        let b = Point::<i64> {
             x: Some(18),
             y: Some(77),
             a: 5,
@@ -137,19 +299,19 @@ mod tests {
     }
     #[test]
     fn test_point_add() {
-        let a = Point {
+        let a = Point::<i64> {
             x: None,
             y: None,
             a: 5,
             b: 7,
         };
-        let b = Point {
+        let b = Point::<i64> {
             x: Some(2),
             y: Some(5),
             a: 5,
             b: 7,
         };
-        let c = Point {
+        let c = Point::<i64> {
             x: Some(2),
             y: Some(-5),
             a: 5,
@@ -159,13 +321,13 @@ mod tests {
         assert_eq!((b + a).unwrap(), b);
         assert_eq!((b + c).unwrap(), a);
 
-        let a = Point {
+        let a = Point::<i64> {
             x: Some(3),
             y: Some(7),
             a: 5,
             b: 7,
         };
-        let b = Point {
+        let b = Point::<i64> {
             x: Some(-1),
             y: Some(-1),
             a: 5,
@@ -173,7 +335,7 @@ mod tests {
         };
         assert_eq!(
             (a + b).unwrap(),
-            Point {
+            Point::<i64> {
                 x: Some(2),
                 y: Some(-5),
                 a: 5,
@@ -181,7 +343,7 @@ mod tests {
             }
         );
 
-        let a = Point {
+        let a = Point::<i64> {
             x: Some(-1),
             y: Some(-1),
             a: 5,
@@ -189,7 +351,7 @@ mod tests {
         };
         assert_eq!(
             (a + a).unwrap(),
-            Point {
+            Point::<i64> {
                 x: Some(18),
                 y: Some(77),
                 a: 5,